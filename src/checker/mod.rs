@@ -0,0 +1,348 @@
+// The type checking pass: walks the lowered AST with a scoped symbol table,
+// inferring expression types and collecting every diagnostic it finds
+// rather than aborting on the first one.
+
+use crate::diagnostics::PyRustError;
+use crate::parser::ast::{Expr, Stmt};
+use crate::symbols::SymbolTable;
+use crate::types::Type;
+
+/// Runs the checking pass over a module's statements.
+pub struct Checker {
+    errors: Vec<PyRustError>,
+}
+
+impl Checker {
+    pub fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Checks a whole module, returning every diagnostic found.
+    pub fn check(&mut self, stmts: &[Stmt]) -> Vec<PyRustError> {
+        let mut symbols = SymbolTable::new();
+        self.check_block(stmts, &mut symbols, None);
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Checks a block of statements. `declared_return` is `Some` when this
+    /// block is a function body, so `return` statements can be checked
+    /// against it.
+    fn check_block(&mut self, stmts: &[Stmt], symbols: &mut SymbolTable, declared_return: Option<&Type>) {
+        // Python only runs a function's body at call time, by which point
+        // every def/assignment elsewhere in the enclosing block has already
+        // executed. Pre-register this block's own names before checking any
+        // statement's body, so forward references (a function calling one
+        // defined later, or using a constant defined later) don't get
+        // flagged as undefined just because of top-to-bottom source order.
+        self.hoist_block(stmts, symbols);
+
+        for stmt in stmts {
+            self.check_stmt(stmt, symbols, declared_return);
+        }
+    }
+
+    /// Pre-registers the names this block will define, without checking
+    /// any bodies or inferring expression types yet.
+    fn hoist_block(&self, stmts: &[Stmt], symbols: &mut SymbolTable) {
+        for stmt in stmts {
+            match stmt {
+                // The function's own name isn't type-checked as a callable
+                // yet (no signature table); record it so calls to it at
+                // least resolve as defined, from anywhere in this block.
+                Stmt::FunctionDef { name, .. } => {
+                    symbols.define(name.clone(), Type::Unknown);
+                }
+                Stmt::AnnAssign {
+                    target, annotation, ..
+                } => {
+                    symbols.define(target.clone(), Type::from_annotation(annotation));
+                }
+                // The assigned value's type isn't known until its own
+                // statement actually runs; `Unknown` just establishes that
+                // the name exists so a forward reference isn't mistaken for
+                // an undefined one.
+                Stmt::Assign { targets, .. } => {
+                    for target in targets {
+                        symbols.define(target.clone(), Type::Unknown);
+                    }
+                }
+                Stmt::Return { .. } | Stmt::Expr { .. } => {}
+            }
+        }
+    }
+
+    fn check_stmt(&mut self, stmt: &Stmt, symbols: &mut SymbolTable, declared_return: Option<&Type>) {
+        match stmt {
+            Stmt::FunctionDef {
+                args,
+                returns,
+                body,
+                ..
+            } => {
+                // The function's own name is already registered in the
+                // enclosing scope by `hoist_block`.
+                symbols.push_scope();
+                for arg in args {
+                    let ty = arg
+                        .annotation
+                        .as_ref()
+                        .map(Type::from_annotation)
+                        .unwrap_or(Type::Unknown);
+                    symbols.define(arg.name.clone(), ty);
+                }
+
+                let declared_return = returns.as_ref().map(Type::from_annotation);
+                self.check_block(body, symbols, declared_return.as_ref());
+                symbols.pop_scope();
+            }
+
+            Stmt::Return { value, span } => {
+                let actual = value
+                    .as_ref()
+                    .map(|v| self.infer_expr(v, symbols))
+                    .unwrap_or(Type::None);
+
+                if let Some(declared) = declared_return {
+                    if !declared.is_assignable_from(&actual) {
+                        // Point at the returned expression itself, falling
+                        // back to the bare `return` when there's none.
+                        let span = value.as_ref().map_or_else(|| span.clone(), |v| v.span().clone());
+                        self.errors.push(PyRustError::TypeError {
+                            span,
+                            message: format!(
+                                "expected return type `{declared}`, found `{actual}`"
+                            ),
+                        });
+                    }
+                }
+            }
+
+            Stmt::AnnAssign {
+                target,
+                annotation,
+                value,
+                ..
+            } => {
+                let declared = Type::from_annotation(annotation);
+
+                if let Some(value) = value {
+                    let inferred = self.infer_expr(value, symbols);
+                    if !declared.is_assignable_from(&inferred) {
+                        self.errors.push(PyRustError::TypeError {
+                            span: value.span().clone(),
+                            message: format!(
+                                "expected `{declared}`, found `{inferred}` in assignment to `{target}`"
+                            ),
+                        });
+                    }
+                }
+
+                symbols.define(target.clone(), declared);
+            }
+
+            Stmt::Assign { targets, value, .. } => {
+                let inferred = self.infer_expr(value, symbols);
+
+                for target in targets {
+                    if let Some(declared) = symbols.lookup(target) {
+                        if !declared.is_assignable_from(&inferred) {
+                            self.errors.push(PyRustError::TypeError {
+                                span: value.span().clone(),
+                                message: format!(
+                                    "expected `{declared}`, found `{inferred}` in assignment to `{target}`"
+                                ),
+                            });
+                            continue;
+                        }
+                    }
+                    symbols.define(target.clone(), inferred.clone());
+                }
+            }
+
+            Stmt::Expr { value, .. } => {
+                self.infer_expr(value, symbols);
+            }
+        }
+    }
+
+    fn infer_expr(&mut self, expr: &Expr, symbols: &SymbolTable) -> Type {
+        match expr {
+            Expr::Constant { value, .. } => Type::from_constant(value),
+
+            Expr::Name { id, span } => match symbols.lookup(id) {
+                Some(ty) => ty.clone(),
+                None => {
+                    self.errors.push(PyRustError::UndefinedName {
+                        name: id.clone(),
+                        span: span.clone(),
+                    });
+                    Type::Unknown
+                }
+            },
+
+            Expr::BinOp { left, right, .. } => {
+                let left_ty = self.infer_expr(left, symbols);
+                let right_ty = self.infer_expr(right, symbols);
+                Type::numeric_promote(&left_ty, &right_ty)
+            }
+
+            Expr::Call { func, args, .. } => {
+                for arg in args {
+                    self.infer_expr(arg, symbols);
+                }
+                self.infer_call_return(func, symbols)
+            }
+
+            // An expression we don't lower yet; treat it as unknown rather
+            // than guessing a concrete type.
+            Expr::Unknown { .. } => Type::Unknown,
+        }
+    }
+
+    /// The return type of a call. Known builtins resolve to their scalar
+    /// return type directly; anything else is resolved as an ordinary name
+    /// use, so calling an unbound name still raises `UndefinedName` just
+    /// like referencing it any other way would.
+    fn infer_call_return(&mut self, func: &Expr, symbols: &SymbolTable) -> Type {
+        if let Expr::Name { id, .. } = func {
+            match id.as_str() {
+                "int" => return Type::Int,
+                "str" => return Type::Str,
+                "bool" => return Type::Bool,
+                "float" => return Type::Float,
+                _ => {}
+            }
+        }
+
+        self.infer_expr(func, symbols);
+        Type::Unknown
+    }
+}
+
+impl Default for Checker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::PythonParser;
+    use std::path::Path;
+
+    fn check_source(source: &str) -> Vec<PyRustError> {
+        let ast = PythonParser::parse_source(source, Path::new("test.py")).unwrap();
+        Checker::new().check(&ast)
+    }
+
+    #[test]
+    fn flags_undefined_name() {
+        let errors = check_source("y = x\n");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], PyRustError::UndefinedName { .. }));
+    }
+
+    #[test]
+    fn flags_mismatched_annotation() {
+        let errors = check_source("x: int = \"oops\"\n");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], PyRustError::TypeError { .. }));
+    }
+
+    #[test]
+    fn allows_bool_widening_to_int() {
+        let errors = check_source("x: int = True\n");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn allows_unsupported_expression_against_any_annotation() {
+        // `[]` isn't lowered yet; it must not be mistaken for a `None`
+        // literal and flagged against an unrelated annotation.
+        let errors = check_source("x: List[int] = []\n");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn flags_call_to_undefined_function() {
+        let errors = check_source("z = totally_undefined_fn()\n");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], PyRustError::UndefinedName { .. }));
+    }
+
+    #[test]
+    fn allows_function_calling_one_defined_later() {
+        let errors = check_source(
+            r#"
+def main() -> int:
+    return helper()
+
+def helper() -> int:
+    return 1
+"#,
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn allows_mutual_recursion_between_top_level_functions() {
+        let errors = check_source(
+            r#"
+def is_even(n: int) -> bool:
+    return is_odd(n)
+
+def is_odd(n: int) -> bool:
+    return is_even(n)
+"#,
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn allows_function_using_module_constant_defined_later() {
+        let errors = check_source(
+            r#"
+def get_limit() -> int:
+    return LIMIT
+
+LIMIT: int = 10
+"#,
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn flags_bad_return_type() {
+        let errors = check_source(
+            r#"
+def f() -> int:
+    return "oops"
+"#,
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], PyRustError::TypeError { .. }));
+    }
+
+    #[test]
+    fn reports_every_error_in_one_pass() {
+        let errors = check_source(
+            r#"
+x: int = "oops"
+y = z
+"#,
+        );
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn accepts_well_typed_function() {
+        let errors = check_source(
+            r#"
+def add(a: int, b: int) -> int:
+    return a + b
+"#,
+        );
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+// Incremental query database: memoizes per-file source text, LineIndex, and
+// parsed AST so unchanged files aren't re-indexed and re-parsed on every
+// query. The source is still read on every query to compare its hash.
+//
+// Modeled after red-knot's `source_text`/`parsed_module` queries: each query
+// is keyed by file path and only recomputes when the file's content hash
+// changes, which lets a watch mode or language server re-run checks on a
+// whole project cheaply after a single file edit.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use crate::diagnostics::Result;
+use crate::parser::{ast::Stmt, PythonParser};
+use crate::utils::LineIndex;
+
+/// The cached state for a single file.
+struct FileEntry {
+    source: String,
+    hash: u64,
+    line_index: LineIndex,
+    /// The parsed module, or `None` if parsing hasn't succeeded yet for the
+    /// currently cached source (e.g. the file has a syntax error). Kept
+    /// separate from `source`/`line_index` so a parse failure doesn't stop
+    /// those from being served out of the cache.
+    module: Option<Vec<Stmt>>,
+}
+
+/// A query database that memoizes source text, [`LineIndex`], and parsed
+/// modules per file path, invalidating an entry only when the file's
+/// content hash changes.
+#[derive(Default)]
+pub struct Database {
+    entries: HashMap<PathBuf, FileEntry>,
+    /// In-memory source overrides, set via [`Database::set_source_override`],
+    /// that take precedence over the file on disk (no disk read).
+    overrides: HashMap<PathBuf, String>,
+}
+
+impl Database {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the source text for `path` with in-memory content instead
+    /// of reading it from disk. Lays the groundwork for a watch mode /
+    /// language server where edits arrive from the editor buffer, not disk.
+    pub fn set_source_override(&mut self, path: impl Into<PathBuf>, source: impl Into<String>) {
+        self.overrides.insert(path.into(), source.into());
+    }
+
+    /// Removes a previously set in-memory override, falling back to disk.
+    pub fn clear_source_override(&mut self, path: &Path) {
+        self.overrides.remove(path);
+    }
+
+    /// The (possibly cached) source text for `path`.
+    pub fn source_text(&mut self, path: &Path) -> Result<&str> {
+        self.refresh_source(path)?;
+        Ok(&self.entries[path].source)
+    }
+
+    /// The (possibly cached) [`LineIndex`] for `path`.
+    pub fn line_index(&mut self, path: &Path) -> Result<&LineIndex> {
+        self.refresh_source(path)?;
+        Ok(&self.entries[path].line_index)
+    }
+
+    /// The (possibly cached) parsed module for `path`.
+    pub fn parsed_module(&mut self, path: &Path) -> Result<&[Stmt]> {
+        self.refresh_source(path)?;
+
+        let entry = self.entries.get_mut(path).expect("just refreshed");
+        if entry.module.is_none() {
+            entry.module = Some(PythonParser::parse_source(&entry.source, path)?);
+        }
+
+        Ok(entry.module.as_deref().expect("just parsed"))
+    }
+
+    /// Reads the current source (override or disk) and, if its content hash
+    /// differs from what's cached, recomputes the source/[`LineIndex`]
+    /// entry. Parsing is handled separately by [`Database::parsed_module`]
+    /// so that a syntax error doesn't prevent `source_text`/`line_index`
+    /// from being served out of the cache.
+    fn refresh_source(&mut self, path: &Path) -> Result<()> {
+        let source = match self.overrides.get(path) {
+            Some(source) => source.clone(),
+            None => fs::read_to_string(path)?,
+        };
+        let hash = Self::hash_source(&source);
+
+        let up_to_date = self
+            .entries
+            .get(path)
+            .is_some_and(|entry| entry.hash == hash);
+
+        if !up_to_date {
+            let line_index = LineIndex::new(&source);
+            self.entries.insert(
+                path.to_path_buf(),
+                FileEntry {
+                    source,
+                    hash,
+                    line_index,
+                    module: None,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    fn hash_source(source: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn caches_source_override_without_disk_access() {
+        let mut db = Database::new();
+        let path = PathBuf::from("<memory>/scratch.py");
+        db.set_source_override(&path, "x: int = 1\n");
+
+        assert_eq!(db.source_text(&path).unwrap(), "x: int = 1\n");
+        assert_eq!(db.parsed_module(&path).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn reparses_only_when_content_changes() {
+        let mut db = Database::new();
+        let path = PathBuf::from("<memory>/scratch.py");
+
+        db.set_source_override(&path, "x: int = 1\n");
+        let first_module = db.parsed_module(&path).unwrap().as_ptr();
+        let first_hash = db.entries[&path].hash;
+
+        // Re-querying with the same content must not change the cached hash
+        // and must hand back the cached parse rather than a fresh one.
+        let second_module = db.parsed_module(&path).unwrap().as_ptr();
+        assert_eq!(db.entries[&path].hash, first_hash);
+        assert_eq!(second_module, first_module);
+
+        db.set_source_override(&path, "x: int = 2\n");
+        db.parsed_module(&path).unwrap();
+        assert_ne!(db.entries[&path].hash, first_hash);
+    }
+}
@@ -1,42 +1,34 @@
 // Error types
 use thiserror::Error;
-use std::path::PathBuf;
+
+use crate::utils::SourceSpan;
 
 #[derive(Error, Debug)]
 pub enum PyRustError {
-    #[error("Parse error at {location}: {message}")]
-    ParseError {
-        location: SourceLocation,
-        message: String,
-    },
+    #[error("Parse error at {span}: {message}")]
+    ParseError { span: SourceSpan, message: String },
 
-    #[error("Type error at {location}: {message}")]
-    TypeError {
-        location: SourceLocation,
-        message: String,
-    },
+    #[error("Type error at {span}: {message}")]
+    TypeError { span: SourceSpan, message: String },
 
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
-    #[error("Undefined name '{name}' at {location}")]
-    UndefinedName {
-        name: String,
-        location: SourceLocation,
-    },
-}
-
-#[derive(Debug, Clone)]
-pub struct SourceLocation {
-    pub file: PathBuf,
-    pub line: usize,
-    pub column: usize,
+    #[error("Undefined name '{name}' at {span}")]
+    UndefinedName { name: String, span: SourceSpan },
 }
 
-impl std::fmt::Display for SourceLocation {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+impl PyRustError {
+    /// The span the diagnostic points at, if any (IO errors have no
+    /// location in the source).
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            PyRustError::ParseError { span, .. }
+            | PyRustError::TypeError { span, .. }
+            | PyRustError::UndefinedName { span, .. } => Some(span),
+            PyRustError::IoError(_) => None,
+        }
     }
 }
 
-pub type Result<T> = std::result::Result<T, PyRustError>;
\ No newline at end of file
+pub type Result<T> = std::result::Result<T, PyRustError>;
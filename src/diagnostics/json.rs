@@ -0,0 +1,118 @@
+// Serializable diagnostic representation for `--format json`
+
+use serde::Serialize;
+
+use crate::diagnostics::PyRustError;
+
+/// A machine-readable view of a [`PyRustError`], suitable for editors and CI
+/// to consume instead of parsing the colored human-readable text output.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticJson {
+    pub file: String,
+    pub start_line: usize,
+    pub start_col: usize,
+    pub end_line: usize,
+    pub end_col: usize,
+    pub severity: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl DiagnosticJson {
+    /// A stable, tool-facing error code for each [`PyRustError`] variant.
+    fn code_for(error: &PyRustError) -> &'static str {
+        match error {
+            PyRustError::ParseError { .. } => "parse-error",
+            PyRustError::TypeError { .. } => "type-error",
+            PyRustError::UndefinedName { .. } => "undefined-name",
+            PyRustError::IoError(_) => "io-error",
+        }
+    }
+}
+
+impl From<&PyRustError> for DiagnosticJson {
+    fn from(error: &PyRustError) -> Self {
+        let span = error.span();
+
+        Self {
+            file: span
+                .map(|s| s.file.display().to_string())
+                .unwrap_or_default(),
+            start_line: span.map(|s| s.start_line).unwrap_or(0),
+            start_col: span.map(|s| s.start_col).unwrap_or(0),
+            end_line: span.map(|s| s.end_line).unwrap_or(0),
+            end_col: span.map(|s| s.end_col).unwrap_or(0),
+            severity: "error".to_string(),
+            code: Self::code_for(error).to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Serializes a batch of diagnostics as a JSON array.
+pub fn diagnostics_to_json(errors: &[PyRustError]) -> String {
+    let diagnostics: Vec<DiagnosticJson> = errors.iter().map(DiagnosticJson::from).collect();
+    serde_json::to_string_pretty(&diagnostics).unwrap_or_else(|_| "[]".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::SourceSpan;
+    use std::path::PathBuf;
+
+    fn span() -> SourceSpan {
+        SourceSpan::new(PathBuf::from("test.py"), 1, 2, 1, 5)
+    }
+
+    #[test]
+    fn maps_parse_error() {
+        let error = PyRustError::ParseError {
+            span: span(),
+            message: "unexpected token".to_string(),
+        };
+        let json = DiagnosticJson::from(&error);
+
+        assert_eq!(json.code, "parse-error");
+        assert_eq!(json.severity, "error");
+        assert_eq!(json.file, "test.py");
+        assert_eq!((json.start_line, json.start_col), (1, 2));
+        assert_eq!((json.end_line, json.end_col), (1, 5));
+    }
+
+    #[test]
+    fn maps_type_error() {
+        let error = PyRustError::TypeError {
+            span: span(),
+            message: "expected `int`, found `str`".to_string(),
+        };
+        let json = DiagnosticJson::from(&error);
+
+        assert_eq!(json.code, "type-error");
+        assert_eq!(json.severity, "error");
+    }
+
+    #[test]
+    fn maps_undefined_name() {
+        let error = PyRustError::UndefinedName {
+            name: "x".to_string(),
+            span: span(),
+        };
+        let json = DiagnosticJson::from(&error);
+
+        assert_eq!(json.code, "undefined-name");
+        assert_eq!(json.severity, "error");
+    }
+
+    #[test]
+    fn maps_io_error_with_no_span() {
+        let error = PyRustError::IoError(std::io::Error::new(std::io::ErrorKind::NotFound, "nope"));
+        let json = DiagnosticJson::from(&error);
+
+        assert_eq!(json.code, "io-error");
+        assert_eq!(json.severity, "error");
+        assert_eq!(json.file, "");
+        assert_eq!((json.start_line, json.start_col), (0, 0));
+        assert_eq!((json.end_line, json.end_col), (0, 0));
+    }
+}
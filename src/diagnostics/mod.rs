@@ -0,0 +1,9 @@
+// Diagnostic types shared by the parser and checker
+
+mod error;
+mod json;
+mod render;
+
+pub use error::{PyRustError, Result};
+pub use json::{diagnostics_to_json, DiagnosticJson};
+pub use render::{render_span, render_span_uncached};
@@ -0,0 +1,82 @@
+// rustc-style source-snippet rendering for diagnostics
+
+use crate::utils::{LineIndex, SourceSpan};
+
+/// Renders the source line(s) covered by `span`, with a caret (`^`)
+/// underline beneath the offending columns and a line-number gutter,
+/// in the style of rustc's diagnostic emitter.
+///
+/// Falls back to an empty string when `span` is [`SourceSpan::unknown`],
+/// since there's nothing meaningful to slice out of `source`.
+pub fn render_span(source: &str, line_index: &LineIndex, span: &SourceSpan) -> String {
+    if *span == SourceSpan::unknown() {
+        return String::new();
+    }
+
+    let gutter_width = span.end_line.to_string().len();
+
+    let mut out = String::new();
+    for line_no in span.start_line..=span.end_line {
+        let Some(text) = line_index.line_text(source, line_no) else {
+            break;
+        };
+
+        out.push_str(&format!("{:>width$} | {}\n", line_no, text, width = gutter_width));
+
+        let underline_start = if line_no == span.start_line {
+            span.start_col
+        } else {
+            0
+        };
+        // A span crossing multiple lines underlines to the end of every
+        // line except the final one, where it stops at `end_col`.
+        let underline_end = if line_no == span.end_line {
+            span.end_col
+        } else {
+            text.chars().count()
+        };
+        let underline_end = underline_end.max(underline_start);
+
+        let padding = " ".repeat(gutter_width) + " | " + &" ".repeat(underline_start);
+        let carets = "^".repeat((underline_end - underline_start).max(1));
+        out.push_str(&padding);
+        out.push_str(&carets);
+        out.push('\n');
+    }
+
+    // Drop the trailing newline so callers can decide their own spacing.
+    out.pop();
+    out
+}
+
+/// Uses [`LineIndex::new`] internally to build the index from scratch; use
+/// [`render_span`] directly when a [`LineIndex`] is already available (e.g.
+/// from the incremental query database) to avoid rebuilding it.
+pub fn render_span_uncached(source: &str, span: &SourceSpan) -> String {
+    let line_index = LineIndex::new(source);
+    render_span(source, &line_index, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn underlines_single_line_span() {
+        let source = "x: int = \"oops\"";
+        let span = SourceSpan::new(PathBuf::from("test.py"), 1, 9, 1, 15);
+        let rendered = render_span_uncached(source, &span);
+
+        assert_eq!(
+            rendered,
+            "1 | x: int = \"oops\"\n  |          ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn falls_back_on_unknown_span() {
+        let rendered = render_span_uncached("whatever", &SourceSpan::unknown());
+        assert_eq!(rendered, "");
+    }
+}
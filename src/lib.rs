@@ -1,3 +1,4 @@
+pub mod db;
 pub mod diagnostics;
 pub mod parser;
 pub mod symbols;
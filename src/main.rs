@@ -1,7 +1,9 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 use colored::*;
-use pyrust_check::parser::PythonParser;
+use pyrust_check::checker::Checker;
+use pyrust_check::db::Database;
+use pyrust_check::diagnostics::{diagnostics_to_json, render_span, PyRustError};
 
 #[derive(Parser)]
 #[command(name = "pyrust-check")]
@@ -11,10 +13,22 @@ struct Cli {
     #[arg(value_name = "PATH")]
     path: Option<PathBuf>,
 
+    /// Output format for diagnostics
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    format: OutputFormat,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Colored, human-readable text
+    Text,
+    /// A JSON array of diagnostics, for editors and CI
+    Json,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Check Python files for type errors
@@ -31,19 +45,25 @@ enum Commands {
 
 fn main() {
     let cli = Cli::parse();
+    let format = cli.format;
+    // A single Database outlives both subcommands so repeated lookups of
+    // the same file (e.g. a future watch mode re-running check on an
+    // edited path) reuse the cached parse instead of re-parsing; the file
+    // is still read each time to check whether its content changed.
+    let mut db = Database::new();
 
     match cli.command {
         Some(Commands::Parse { path }) => {
-            parse_command(&path);
+            parse_command(&path, format, &mut db);
         }
         // Explicitly handle the subcommand Check
         Some(Commands::Check { path }) => {
-            check_command(&path);
+            check_command(&path, format, &mut db);
         }
         // Handle the case where no subcommand is provided (default behavior)
         None => {
             if let Some(path) = cli.path {
-                check_command(&path);
+                check_command(&path, format, &mut db);
             } else {
                 eprintln!("{}", "Error: Please provide a path to check".red());
                 use clap::CommandFactory;
@@ -54,24 +74,71 @@ fn main() {
     }
 }
 
-fn parse_command(path: &PathBuf) {
-    println!("{} {}", "Parsing:".blue(), path.display());
-    
-    match PythonParser::parse_file(path) {
+fn parse_command(path: &PathBuf, format: OutputFormat, db: &mut Database) {
+    match db.parsed_module(path) {
         Ok(ast) => {
-            println!("{}", "✓ Parsed successfully".green());
-            println!("\nAST has {} statements", ast.len());
-            // In the future we can print the debug view of our simplified AST
-            println!("{:#?}", ast); 
+            if format == OutputFormat::Json {
+                println!("{}", diagnostics_to_json(&[]));
+            } else {
+                println!("{} {}", "Parsing:".blue(), path.display());
+                println!("{}", "✓ Parsed successfully".green());
+                println!("\nAST has {} statements", ast.len());
+                // Print the debug view of our simplified AST
+                println!("{:#?}", ast);
+            }
         }
         Err(e) => {
-            eprintln!("{} {}", "✗ Parse error:".red(), e);
+            print_errors(&[e], path, format, db);
             std::process::exit(1);
         }
     }
 }
 
-fn check_command(path: &PathBuf) {
-    println!("{} {}", "Checking:".blue(), path.display());
-    println!("{}", "Type checking not yet implemented".yellow());
+fn check_command(path: &PathBuf, format: OutputFormat, db: &mut Database) {
+    let errors = match db.parsed_module(path) {
+        Ok(ast) => Checker::new().check(ast),
+        Err(e) => {
+            print_errors(&[e], path, format, db);
+            std::process::exit(1);
+        }
+    };
+
+    if errors.is_empty() {
+        if format == OutputFormat::Json {
+            println!("{}", diagnostics_to_json(&[]));
+        } else {
+            println!("{} {}", "Checking:".blue(), path.display());
+            println!("{}", "✓ No errors found".green());
+        }
+    } else {
+        print_errors(&errors, path, format, db);
+        std::process::exit(1);
+    }
+}
+
+fn print_errors(errors: &[PyRustError], path: &PathBuf, format: OutputFormat, db: &mut Database) {
+    match format {
+        OutputFormat::Json => println!("{}", diagnostics_to_json(errors)),
+        OutputFormat::Text => {
+            // Best-effort: render source snippets by going through the same
+            // Database that just parsed/checked this file, so the cached
+            // source/LineIndex are reused (and so in-memory overrides set
+            // via `set_source_override` render correctly too, since they
+            // have nothing to read from disk).
+            let source = db.source_text(path).ok().map(str::to_owned);
+            let line_index = source.as_ref().and_then(|_| db.line_index(path).ok().cloned());
+
+            for error in errors {
+                eprintln!("{} {}", "✗ Error:".red(), error);
+                if let (Some(source), Some(line_index), Some(span)) =
+                    (&source, &line_index, error.span())
+                {
+                    let snippet = render_span(source, line_index, span);
+                    if !snippet.is_empty() {
+                        eprintln!("{}", snippet);
+                    }
+                }
+            }
+        }
+    }
 }
\ No newline at end of file
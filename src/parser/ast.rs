@@ -2,7 +2,6 @@
 // Phase 1: AST definitions
 
 use crate::utils::SourceSpan;
-// use rustpython_parser::ast::{self, Stmt as RustPythonStmt, Expr as RustPythonExpr};
 
 /// Simplified AST node types we care about for MVP
 #[derive(Debug, Clone)]
@@ -14,6 +13,10 @@ pub enum Stmt {
         body: Vec<Stmt>,
         span: SourceSpan,
     },
+    Return {
+        value: Option<Expr>,
+        span: SourceSpan,
+    },
     AnnAssign {
         target: String,
         annotation: TypeAnnotation,
@@ -39,10 +42,20 @@ pub struct Arg {
     pub span: SourceSpan,
 }
 
+/// A type annotation, covering the subset of the Python typing surface the
+/// checker needs to reason about: plain names, subscripted generics
+/// (`List[int]`, `Dict[str, int]`), PEP 604 unions (`int | str`), and
+/// `Optional[T]` (kept distinct from `Union` since it's by far the most
+/// common case and maps 1:1 to `T | None`).
 #[derive(Debug, Clone)]
 pub enum TypeAnnotation {
     Name(String),
-    // Will expand this later for generics, unions, etc.
+    Generic {
+        base: String,
+        args: Vec<TypeAnnotation>,
+    },
+    Union(Vec<TypeAnnotation>),
+    Optional(Box<TypeAnnotation>),
 }
 
 #[derive(Debug, Clone)]
@@ -66,6 +79,27 @@ pub enum Expr {
         args: Vec<Expr>,
         span: SourceSpan,
     },
+    /// An expression we don't lower yet (attribute access, subscript reads,
+    /// list/dict/set literals, comprehensions, f-strings, comparisons, bool
+    /// ops, ...). Kept distinct from a `None` constant so the checker infers
+    /// `Type::Unknown` for it rather than a real `None` literal.
+    Unknown {
+        span: SourceSpan,
+    },
+}
+
+impl Expr {
+    /// The span this expression covers, so callers can point a diagnostic
+    /// at the specific sub-expression rather than its enclosing statement.
+    pub fn span(&self) -> &SourceSpan {
+        match self {
+            Expr::Name { span, .. }
+            | Expr::Constant { span, .. }
+            | Expr::BinOp { span, .. }
+            | Expr::Call { span, .. }
+            | Expr::Unknown { span, .. } => span,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -77,12 +111,18 @@ pub enum Constant {
     None,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BinOp {
     Add,
     Sub,
     Mult,
     Div,
+    FloorDiv,
+    Mod,
+    Pow,
+    BitOr,
+    BitXor,
+    BitAnd,
+    LShift,
+    RShift,
 }
-
-// TODO: Implement conversion traits From<rustpython_ast::Stmt> for Stmt
\ No newline at end of file
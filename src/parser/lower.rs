@@ -0,0 +1,239 @@
+// Lowers the raw rustpython AST into `crate::parser::ast`, populating every
+// node's SourceSpan via a precomputed LineIndex and expanding type
+// annotations beyond bare names.
+
+use rustpython_parser::ast as rast;
+use rustpython_parser::ast::Ranged;
+use rustpython_parser::text_size::TextRange;
+use std::path::Path;
+
+use super::ast as past;
+use crate::utils::{LineIndex, SourceSpan};
+
+/// Converts a single file's rustpython AST into our simplified AST. Carries
+/// the source, its [`LineIndex`], and the file path so every lowered node
+/// can get a proper `SourceSpan` — that context is why this is a struct
+/// method rather than a plain `From` impl.
+pub struct Lowerer<'a> {
+    source: &'a str,
+    line_index: &'a LineIndex,
+    file: &'a Path,
+}
+
+impl<'a> Lowerer<'a> {
+    pub fn new(source: &'a str, line_index: &'a LineIndex, file: &'a Path) -> Self {
+        Self {
+            source,
+            line_index,
+            file,
+        }
+    }
+
+    pub fn lower_stmts(&self, stmts: Vec<rast::Stmt>) -> Vec<past::Stmt> {
+        stmts.into_iter().filter_map(|s| self.lower_stmt(s)).collect()
+    }
+
+    fn span(&self, range: TextRange) -> SourceSpan {
+        let (start_line, start_col) = self
+            .line_index
+            .offset_to_line_col(self.source, range.start().to_usize());
+        let (end_line, end_col) = self
+            .line_index
+            .offset_to_line_col(self.source, range.end().to_usize());
+        SourceSpan::new(self.file.to_path_buf(), start_line, start_col, end_line, end_col)
+    }
+
+    fn lower_stmt(&self, stmt: rast::Stmt) -> Option<past::Stmt> {
+        match stmt {
+            rast::Stmt::FunctionDef(f) => Some(past::Stmt::FunctionDef {
+                name: f.name.to_string(),
+                args: self.lower_args(*f.args),
+                returns: f.returns.map(|r| self.lower_annotation(*r)),
+                body: self.lower_stmts(f.body),
+                span: self.span(f.range),
+            }),
+            rast::Stmt::Return(r) => Some(past::Stmt::Return {
+                value: r.value.map(|v| self.lower_expr(*v)),
+                span: self.span(r.range),
+            }),
+            rast::Stmt::AnnAssign(a) => Some(past::Stmt::AnnAssign {
+                target: self.lower_target_name(&a.target)?,
+                annotation: self.lower_annotation(*a.annotation),
+                value: a.value.map(|v| self.lower_expr(*v)),
+                span: self.span(a.range),
+            }),
+            rast::Stmt::Assign(a) => Some(past::Stmt::Assign {
+                targets: a
+                    .targets
+                    .iter()
+                    .filter_map(|t| self.lower_target_name(t))
+                    .collect(),
+                value: self.lower_expr(*a.value),
+                span: self.span(a.range),
+            }),
+            rast::Stmt::Expr(e) => Some(past::Stmt::Expr {
+                value: self.lower_expr(*e.value),
+                span: self.span(e.range),
+            }),
+            // Other statement kinds (If, While, For, Class, imports, ...)
+            // aren't needed by the checker yet; we'll add them as needed.
+            _ => None,
+        }
+    }
+
+    fn lower_target_name(&self, expr: &rast::Expr) -> Option<String> {
+        match expr {
+            rast::Expr::Name(n) => Some(n.id.to_string()),
+            _ => None,
+        }
+    }
+
+    fn lower_args(&self, args: rast::Arguments) -> Vec<past::Arg> {
+        args.args
+            .into_iter()
+            .map(|arg_with_default| {
+                let arg = arg_with_default.def;
+                past::Arg {
+                    name: arg.arg.to_string(),
+                    annotation: arg.annotation.map(|a| self.lower_annotation(*a)),
+                    span: self.span(arg.range),
+                }
+            })
+            .collect()
+    }
+
+    fn lower_expr(&self, expr: rast::Expr) -> past::Expr {
+        match expr {
+            rast::Expr::Name(n) => past::Expr::Name {
+                id: n.id.to_string(),
+                span: self.span(n.range),
+            },
+            rast::Expr::Constant(c) => past::Expr::Constant {
+                value: self.lower_constant(c.value),
+                span: self.span(c.range),
+            },
+            rast::Expr::BinOp(b) => past::Expr::BinOp {
+                left: Box::new(self.lower_expr(*b.left)),
+                op: self.lower_operator(b.op),
+                right: Box::new(self.lower_expr(*b.right)),
+                span: self.span(b.range),
+            },
+            rast::Expr::Call(c) => past::Expr::Call {
+                func: Box::new(self.lower_expr(*c.func)),
+                args: c.args.into_iter().map(|a| self.lower_expr(a)).collect(),
+                span: self.span(c.range),
+            },
+            // Anything we don't lower yet (attribute access, subscripts in
+            // expression position, list/dict/set literals, comprehensions,
+            // f-strings, comparisons, bool ops, ...) collapses to `Unknown`
+            // rather than panicking; the checker treats it as "unknown"
+            // going forward instead of a real `None` literal.
+            other => past::Expr::Unknown {
+                span: self.span(other.range()),
+            },
+        }
+    }
+
+    fn lower_constant(&self, value: rast::Constant) -> past::Constant {
+        match value {
+            rast::Constant::None => past::Constant::None,
+            rast::Constant::Bool(b) => past::Constant::Bool(b),
+            rast::Constant::Str(s) => past::Constant::Str(s),
+            rast::Constant::Int(i) => past::Constant::Int(i.to_string().parse().unwrap_or(0)),
+            rast::Constant::Float(f) => past::Constant::Float(f),
+            // Bytes/complex/tuple/ellipsis literals aren't part of the
+            // typed MVP surface yet.
+            _ => past::Constant::None,
+        }
+    }
+
+    fn lower_operator(&self, op: rast::Operator) -> past::BinOp {
+        match op {
+            rast::Operator::Add => past::BinOp::Add,
+            rast::Operator::Sub => past::BinOp::Sub,
+            rast::Operator::Mult => past::BinOp::Mult,
+            rast::Operator::Div => past::BinOp::Div,
+            rast::Operator::FloorDiv => past::BinOp::FloorDiv,
+            rast::Operator::Mod => past::BinOp::Mod,
+            rast::Operator::Pow => past::BinOp::Pow,
+            rast::Operator::BitOr => past::BinOp::BitOr,
+            rast::Operator::BitXor => past::BinOp::BitXor,
+            rast::Operator::BitAnd => past::BinOp::BitAnd,
+            rast::Operator::LShift => past::BinOp::LShift,
+            rast::Operator::RShift => past::BinOp::RShift,
+            // MatMult has no MVP equivalent; treat it as multiplication.
+            rast::Operator::MatMult => past::BinOp::Mult,
+        }
+    }
+
+    /// Lowers an annotation expression into our `TypeAnnotation`, expanding
+    /// subscripted generics (`List[int]`), PEP 604 unions (`int | None`),
+    /// and `Optional[T]`.
+    fn lower_annotation(&self, expr: rast::Expr) -> past::TypeAnnotation {
+        match expr {
+            rast::Expr::Name(n) => past::TypeAnnotation::Name(n.id.to_string()),
+            rast::Expr::Constant(c) if matches!(c.value, rast::Constant::None) => {
+                past::TypeAnnotation::Name("None".to_string())
+            }
+            rast::Expr::BinOp(b) if matches!(b.op, rast::Operator::BitOr) => {
+                let members = self
+                    .flatten_union(rast::Expr::BinOp(b))
+                    .into_iter()
+                    .map(|e| self.lower_annotation(e))
+                    .collect();
+                past::TypeAnnotation::Union(members)
+            }
+            rast::Expr::Subscript(s) => {
+                let base = self.annotation_base_name(&s.value);
+                let args = self.subscript_args(*s.slice);
+
+                if base == "Optional" {
+                    let inner = args
+                        .into_iter()
+                        .next()
+                        .map(|e| self.lower_annotation(e))
+                        .unwrap_or_else(|| past::TypeAnnotation::Name("Any".to_string()));
+                    past::TypeAnnotation::Optional(Box::new(inner))
+                } else {
+                    past::TypeAnnotation::Generic {
+                        base,
+                        args: args.into_iter().map(|e| self.lower_annotation(e)).collect(),
+                    }
+                }
+            }
+            // Anything more exotic (attribute access like `typing.List`,
+            // string forward-references, ...) falls back to `Any`.
+            _ => past::TypeAnnotation::Name("Any".to_string()),
+        }
+    }
+
+    /// Flattens a left-associative chain of `BinOp(BitOr)` nodes (how
+    /// `int | str | None` parses) into its individual members.
+    fn flatten_union(&self, expr: rast::Expr) -> Vec<rast::Expr> {
+        match expr {
+            rast::Expr::BinOp(b) if matches!(b.op, rast::Operator::BitOr) => {
+                let mut members = self.flatten_union(*b.left);
+                members.extend(self.flatten_union(*b.right));
+                members
+            }
+            other => vec![other],
+        }
+    }
+
+    fn annotation_base_name(&self, expr: &rast::Expr) -> String {
+        match expr {
+            rast::Expr::Name(n) => n.id.to_string(),
+            rast::Expr::Attribute(a) => a.attr.to_string(),
+            _ => "Any".to_string(),
+        }
+    }
+
+    /// `Dict[str, int]`'s slice is a tuple of the two args; `List[int]`'s
+    /// slice is the single arg directly.
+    fn subscript_args(&self, slice: rast::Expr) -> Vec<rast::Expr> {
+        match slice {
+            rast::Expr::Tuple(t) => t.elts,
+            other => vec![other],
+        }
+    }
+}
@@ -1,5 +1,6 @@
 // Phase 1: Parser implementation will go here
 pub mod ast;
+mod lower;
 mod parser_impl;
 
 pub use parser_impl::PythonParser;
@@ -1,23 +1,33 @@
 use rustpython_parser::{parse, Mode, ParseError, ast::Mod};
 use rustpython_parser::text_size::TextRange;
-use std::fs;
 use std::path::Path;
-use crate::diagnostics::{PyRustError, Result, SourceLocation};
+use crate::diagnostics::{PyRustError, Result};
+use crate::parser::ast::Stmt;
+use crate::parser::lower::Lowerer;
+use crate::utils::{LineIndex, SourceSpan};
 
 pub struct PythonParser;
 
 impl PythonParser {
-    /// Reads a file and parses it into a RustPython AST
-    pub fn parse_file(path: &Path) -> Result<Vec<rustpython_parser::ast::Stmt>> {
-        let source = fs::read_to_string(path)?;
-        Self::parse_source(&source, path)
+    /// Parses a string of source code into our simplified, fully-spanned AST.
+    ///
+    /// Callers that need to read a file from disk (or an in-memory override)
+    /// go through [`crate::db::Database`], which already has to read the
+    /// source to decide whether the cached parse is still valid; there's no
+    /// standalone "parse this path" entry point to keep that logic in one
+    /// place.
+    pub fn parse_source(source: &str, path: &Path) -> Result<Vec<Stmt>> {
+        let raw_body = Self::parse_raw(source, path)?;
+
+        let line_index = LineIndex::new(source);
+        let lowerer = Lowerer::new(source, &line_index, path);
+        Ok(lowerer.lower_stmts(raw_body))
     }
 
-    /// Parses a string of source code into a RustPython AST
-    pub fn parse_source(
-        source: &str,
-        path: &Path,
-    ) -> Result<Vec<rustpython_parser::ast::Stmt>> {
+    /// Parses source into the raw rustpython AST, before lowering. Kept
+    /// internal: nothing outside the parser module should depend on
+    /// rustpython's AST shape.
+    fn parse_raw(source: &str, path: &Path) -> Result<Vec<rustpython_parser::ast::Stmt>> {
         // Mode::Module is standard for .py files
         let parsed = parse(source, Mode::Module, path.to_str().unwrap_or("<unknown>"))
             .map_err(|e| Self::convert_parse_error(e, source, path))?;
@@ -30,52 +40,28 @@ impl PythonParser {
                 // Wrap single expression in an Expr statement
                 Ok(vec![rustpython_parser::ast::Stmt::Expr(rustpython_parser::ast::StmtExpr {
                      range: TextRange::default(),
-                     value: e.body, 
+                     value: e.body,
                  })])
             },
             // Handle other variants (FunctionType, Interactive) as empty for now
-            _ => Ok(vec![]), 
+            _ => Ok(vec![]),
         }
     }
 
     fn convert_parse_error(error: ParseError, source: &str, path: &Path) -> PyRustError {
-        // Convert the byte offset to line and column
+        // Convert the byte offset to line and column via a precomputed index
+        // rather than rescanning the source for every diagnostic. A parse
+        // error only has a single offset, so it becomes a zero-width span.
         let offset = error.offset.to_usize();
-        let (line, column) = Self::offset_to_line_col(source, offset);
-
-        let location = SourceLocation {
-            file: path.to_path_buf(),
-            line,
-            column,
-        };
-        
-        PyRustError::ParseError {
-            location,
-            message: error.error.to_string(),
-        }
-    }
+        let line_index = LineIndex::new(source);
+        let (line, column) = line_index.offset_to_line_col(source, offset);
 
-    /// Helper to calculate line and column from byte offset
-    fn offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
-        if offset == 0 {
-            return (1, 0);
-        }
+        let span = SourceSpan::new(path.to_path_buf(), line, column, line, column);
 
-        let mut line = 1;
-        let mut last_line_start = 0;
-        
-        for (i, c) in source.char_indices() {
-            if i >= offset {
-                break;
-            }
-            if c == '\n' {
-                line += 1;
-                last_line_start = i + 1;
-            }
+        PyRustError::ParseError {
+            span,
+            message: error.error.to_string(),
         }
-        
-        let column = offset.saturating_sub(last_line_start);
-        (line, column)
     }
 }
 
@@ -91,6 +77,7 @@ def add(a: int, b: int) -> int:
 "#;
         let result = PythonParser::parse_source(source, Path::new("test.py"));
         assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 1);
     }
 
     #[test]
@@ -101,16 +88,17 @@ y: str = "hello"
 "#;
         let result = PythonParser::parse_source(source, Path::new("test.py"));
         assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 2);
     }
 
     #[test]
     fn test_parse_syntax_error() {
         let source = "def invalid syntax";
         let result = PythonParser::parse_source(source, Path::new("test.py"));
-        
+
         match result {
-            Err(PyRustError::ParseError { location, .. }) => {
-                assert_eq!(location.line, 1);
+            Err(PyRustError::ParseError { span, .. }) => {
+                assert_eq!(span.start_line, 1);
             }
             _ => panic!("Expected ParseError"),
         }
@@ -123,4 +111,32 @@ y: str = "hello"
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_union_annotation() {
+        let source = "x: int | None = None\n";
+        let result = PythonParser::parse_source(source, Path::new("test.py")).unwrap();
+        match &result[0] {
+            Stmt::AnnAssign { annotation, .. } => {
+                assert!(matches!(annotation, crate::parser::ast::TypeAnnotation::Union(_)));
+            }
+            other => panic!("Expected AnnAssign, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_generic_annotation() {
+        let source = "x: List[int] = []\n";
+        let result = PythonParser::parse_source(source, Path::new("test.py")).unwrap();
+        match &result[0] {
+            Stmt::AnnAssign { annotation, .. } => match annotation {
+                crate::parser::ast::TypeAnnotation::Generic { base, args } => {
+                    assert_eq!(base, "List");
+                    assert_eq!(args.len(), 1);
+                }
+                other => panic!("Expected Generic, got {other:?}"),
+            },
+            other => panic!("Expected AnnAssign, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// Scoped symbol table used by the checker to track defined names and their
+// inferred/declared types per function and module scope.
+
+use std::collections::HashMap;
+
+use crate::types::Type;
+
+/// A stack of scopes (module scope at the bottom, nested function scopes
+/// pushed on top) mapping names to their `Type`.
+///
+/// Lookups walk outward from the innermost scope, matching Python's
+/// name-resolution order for module/function nesting (we don't yet model
+/// classes or closures capturing enclosing-function locals beyond this).
+pub struct SymbolTable {
+    scopes: Vec<HashMap<String, Type>>,
+}
+
+impl SymbolTable {
+    /// Starts a fresh table with just the module scope.
+    pub fn new() -> Self {
+        Self {
+            scopes: vec![HashMap::new()],
+        }
+    }
+
+    pub fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    pub fn pop_scope(&mut self) {
+        debug_assert!(self.scopes.len() > 1, "cannot pop the module scope");
+        self.scopes.pop();
+    }
+
+    /// Defines (or redefines) `name` in the innermost scope.
+    pub fn define(&mut self, name: impl Into<String>, ty: Type) {
+        self.scopes
+            .last_mut()
+            .expect("module scope always present")
+            .insert(name.into(), ty);
+    }
+
+    /// Looks up `name`, searching from the innermost scope outward.
+    pub fn lookup(&self, name: &str) -> Option<&Type> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.lookup(name).is_some()
+    }
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inner_scope_shadows_outer() {
+        let mut table = SymbolTable::new();
+        table.define("x", Type::Str);
+        table.push_scope();
+        table.define("x", Type::Int);
+
+        assert_eq!(table.lookup("x"), Some(&Type::Int));
+        table.pop_scope();
+        assert_eq!(table.lookup("x"), Some(&Type::Str));
+    }
+
+    #[test]
+    fn undefined_name_is_not_found() {
+        let table = SymbolTable::new();
+        assert!(!table.is_defined("missing"));
+    }
+}
@@ -0,0 +1,179 @@
+// The checker's type model
+
+use std::fmt;
+
+use crate::parser::ast::{Constant, TypeAnnotation};
+
+/// A type the checker can reason about. Covers the handful of builtin
+/// scalar types plus the generics/unions `TypeAnnotation` can express.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Int,
+    Str,
+    Bool,
+    Float,
+    None,
+    Generic { base: String, args: Vec<Type> },
+    Union(Vec<Type>),
+    /// A type we couldn't pin down (unannotated parameter, unsupported
+    /// expression, call to a function we don't know the return type of).
+    /// Assignable to and from anything, so it never produces a false
+    /// positive on its own.
+    Unknown,
+}
+
+impl Type {
+    /// Resolves a lowered `TypeAnnotation` into a `Type`.
+    pub fn from_annotation(annotation: &TypeAnnotation) -> Type {
+        match annotation {
+            TypeAnnotation::Name(name) => Self::from_name(name),
+            TypeAnnotation::Generic { base, args } => Type::Generic {
+                base: base.clone(),
+                args: args.iter().map(Type::from_annotation).collect(),
+            },
+            TypeAnnotation::Union(members) => {
+                Type::Union(members.iter().map(Type::from_annotation).collect())
+            }
+            TypeAnnotation::Optional(inner) => {
+                Type::Union(vec![Type::from_annotation(inner), Type::None])
+            }
+        }
+    }
+
+    fn from_name(name: &str) -> Type {
+        match name {
+            "int" => Type::Int,
+            "str" => Type::Str,
+            "bool" => Type::Bool,
+            "float" => Type::Float,
+            "None" => Type::None,
+            _ => Type::Unknown,
+        }
+    }
+
+    /// The type of a literal constant.
+    pub fn from_constant(constant: &Constant) -> Type {
+        match constant {
+            Constant::Int(_) => Type::Int,
+            Constant::Str(_) => Type::Str,
+            Constant::Bool(_) => Type::Bool,
+            Constant::Float(_) => Type::Float,
+            Constant::None => Type::None,
+        }
+    }
+
+    /// Whether a value of type `other` can be used where `self` is
+    /// declared, e.g. for an assignment or a `return`.
+    ///
+    /// `Unknown` is assignable both ways since we couldn't infer it with
+    /// confidence; `bool` widens to `int` and `int` widens to `float`,
+    /// matching Python's numeric tower; a `Union` accepts anything one of
+    /// its members accepts.
+    pub fn is_assignable_from(&self, other: &Type) -> bool {
+        if matches!(self, Type::Unknown) || matches!(other, Type::Unknown) {
+            return true;
+        }
+        if self == other {
+            return true;
+        }
+
+        match (self, other) {
+            (Type::Union(members), _) => members.iter().any(|m| m.is_assignable_from(other)),
+            (_, Type::Union(members)) => members.iter().all(|m| self.is_assignable_from(m)),
+            (Type::Float, Type::Int) | (Type::Float, Type::Bool) => true,
+            (Type::Int, Type::Bool) => true,
+            (Type::Generic { base: b1, args: a1 }, Type::Generic { base: b2, args: a2 }) => {
+                b1 == b2
+                    && a1.len() == a2.len()
+                    && a1.iter().zip(a2).all(|(x, y)| x.is_assignable_from(y))
+            }
+            _ => false,
+        }
+    }
+
+    /// The result type of a numeric `BinOp`, following Python's numeric
+    /// promotion: `bool` promotes to `int`, `int` promotes to `float` when
+    /// mixed with one. Two `str` operands give `str`, whatever the
+    /// operator. Other non-numeric operands produce `Unknown` rather than
+    /// a false-positive type error, since the checker doesn't model
+    /// operator overloading (`__add__` on user types, etc.).
+    pub fn numeric_promote(left: &Type, right: &Type) -> Type {
+        match (left, right) {
+            (Type::Float, Type::Float | Type::Int | Type::Bool)
+            | (Type::Int | Type::Bool, Type::Float) => Type::Float,
+            (Type::Int, Type::Int | Type::Bool) | (Type::Bool, Type::Int) => Type::Int,
+            (Type::Bool, Type::Bool) => Type::Int,
+            (Type::Str, Type::Str) => Type::Str,
+            (Type::Unknown, _) | (_, Type::Unknown) => Type::Unknown,
+            _ => Type::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Int => write!(f, "int"),
+            Type::Str => write!(f, "str"),
+            Type::Bool => write!(f, "bool"),
+            Type::Float => write!(f, "float"),
+            Type::None => write!(f, "None"),
+            Type::Generic { base, args } => {
+                write!(f, "{base}[")?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{arg}")?;
+                }
+                write!(f, "]")
+            }
+            Type::Union(members) => {
+                for (i, member) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{member}")?;
+                }
+                Ok(())
+            }
+            Type::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bool_widens_to_int_widens_to_float() {
+        assert!(Type::Int.is_assignable_from(&Type::Bool));
+        assert!(Type::Float.is_assignable_from(&Type::Int));
+        assert!(!Type::Int.is_assignable_from(&Type::Float));
+    }
+
+    #[test]
+    fn optional_accepts_none_and_inner() {
+        let optional_int = Type::from_annotation(&TypeAnnotation::Optional(Box::new(
+            TypeAnnotation::Name("int".to_string()),
+        )));
+        assert!(optional_int.is_assignable_from(&Type::Int));
+        assert!(optional_int.is_assignable_from(&Type::None));
+        assert!(!optional_int.is_assignable_from(&Type::Str));
+    }
+
+    #[test]
+    fn generic_args_must_match() {
+        let list_int = Type::Generic {
+            base: "List".to_string(),
+            args: vec![Type::Int],
+        };
+        let list_str = Type::Generic {
+            base: "List".to_string(),
+            args: vec![Type::Str],
+        };
+        assert!(!list_int.is_assignable_from(&list_str));
+        assert!(list_int.is_assignable_from(&list_int.clone()));
+    }
+}
@@ -0,0 +1,147 @@
+// Precomputed offset <-> line/column mapping
+
+/// Maps byte offsets into a source string to `(line, column)` pairs (1-based
+/// line, 0-based column) and back, without rescanning the source on every
+/// lookup.
+///
+/// Built once per source via [`LineIndex::new`]; offset -> position lookups
+/// are then a binary search over the recorded line-start offsets rather
+/// than a linear scan, which matters when a single file produces many
+/// diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Byte offset of the start of each line (line 0 is always offset 0).
+    line_starts: Vec<usize>,
+    /// Total length of the source in bytes, used to clamp out-of-range offsets.
+    len: usize,
+}
+
+impl LineIndex {
+    /// Scans `source` once and records the byte offset of every line start.
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        for (i, c) in source.char_indices() {
+            if c == '\n' {
+                line_starts.push(i + 1);
+            }
+        }
+
+        Self {
+            line_starts,
+            len: source.len(),
+        }
+    }
+
+    /// Converts a byte offset into a `(line, column)` pair, with a 1-based
+    /// line and a 0-based column.
+    ///
+    /// The column is a count of `char`s between the line start and the
+    /// offset, so multi-byte UTF-8 sequences count as a single column.
+    /// Offsets past the end of the source are clamped to the last line.
+    pub fn offset_to_line_col(&self, source: &str, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.len);
+
+        // Greatest line start <= offset.
+        let line_idx = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line_idx];
+
+        let column = source[line_start..offset].chars().count();
+        (line_idx + 1, column)
+    }
+
+    /// Returns the text of 1-based `line` within `source`, excluding its
+    /// trailing newline, or `None` if `line` is past the end of the source.
+    ///
+    /// Used by the diagnostic renderer to slice out just the line(s) a
+    /// [`SourceSpan`](crate::utils::SourceSpan) covers without rescanning
+    /// `source` for line boundaries.
+    pub fn line_text<'a>(&self, source: &'a str, line: usize) -> Option<&'a str> {
+        let line_idx = line.checked_sub(1)?;
+        let line_start = *self.line_starts.get(line_idx)?;
+        let line_end = self
+            .line_starts
+            .get(line_idx + 1)
+            .map(|&next| next.saturating_sub(1))
+            .unwrap_or(self.len);
+
+        Some(source[line_start..line_end.max(line_start)].trim_end_matches('\r'))
+    }
+
+    /// Converts a `(line, column)` pair (1-based line, 0-based column) back
+    /// into a byte offset.
+    ///
+    /// `line` is clamped to the last available line; `column` is clamped to
+    /// the length (in chars) of that line.
+    pub fn line_col_to_offset(&self, source: &str, line: usize, column: usize) -> usize {
+        let line_idx = line.saturating_sub(1).min(self.line_starts.len() - 1);
+        let line_start = self.line_starts[line_idx];
+        let line_end = self
+            .line_starts
+            .get(line_idx + 1)
+            .map(|&next| next.saturating_sub(1))
+            .unwrap_or(self.len);
+
+        let line_text = &source[line_start..line_end.max(line_start)];
+        match line_text.char_indices().nth(column) {
+            Some((byte_offset, _)) => line_start + byte_offset,
+            None => line_end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_line_source() {
+        let source = "hello world";
+        let index = LineIndex::new(source);
+        assert_eq!(index.offset_to_line_col(source, 0), (1, 0));
+        assert_eq!(index.offset_to_line_col(source, 6), (1, 6));
+    }
+
+    #[test]
+    fn multiple_lines() {
+        let source = "line one\nline two\nline three";
+        let index = LineIndex::new(source);
+        assert_eq!(index.offset_to_line_col(source, 0), (1, 0));
+        assert_eq!(index.offset_to_line_col(source, 9), (2, 0));
+        assert_eq!(index.offset_to_line_col(source, 14), (2, 5));
+        assert_eq!(index.offset_to_line_col(source, 18), (3, 0));
+        assert_eq!(index.offset_to_line_col(source, 19), (3, 1));
+    }
+
+    #[test]
+    fn clamps_offset_past_eof() {
+        let source = "short";
+        let index = LineIndex::new(source);
+        assert_eq!(
+            index.offset_to_line_col(source, 1000),
+            index.offset_to_line_col(source, source.len())
+        );
+    }
+
+    #[test]
+    fn handles_multi_byte_utf8() {
+        let source = "caf\u{e9} latte\nsecond";
+        let index = LineIndex::new(source);
+        // "caf\u{e9}" is 4 chars but 5 bytes; the column counts chars, so the
+        // byte offset where `\u{e9}` *starts* is column 3, and the byte
+        // offset just past it (5) is column 4, not 5.
+        let e_acute_byte_offset = "caf".len();
+        assert_eq!(index.offset_to_line_col(source, e_acute_byte_offset), (1, 3));
+        let after_e_acute_byte_offset = "caf\u{e9}".len();
+        assert_eq!(index.offset_to_line_col(source, after_e_acute_byte_offset), (1, 4));
+    }
+
+    #[test]
+    fn round_trips_line_col_to_offset() {
+        let source = "line one\nline two\nline three";
+        let index = LineIndex::new(source);
+        for offset in [0, 5, 9, 14, 19, source.len()] {
+            let (line, col) = index.offset_to_line_col(source, offset);
+            assert_eq!(index.line_col_to_offset(source, line, col), offset);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+// Shared utilities used across the parser and checker
+
+mod line_index;
+mod span;
+
+pub use line_index::LineIndex;
+pub use span::SourceSpan;